@@ -0,0 +1,93 @@
+//! Structural representation of a parsed Rust source file: a flat-ish tree
+//! of [`Item`]s mirroring the declarations the lexer/parser can recognize
+//! (structs, enums, traits, impls, functions, modules, macros, consts,
+//! statics, type aliases and `use` statements), each carrying enough
+//! metadata to answer "what is this, where does it live, what shape does
+//! it have" without re-reading the source.
+
+use serde::Serialize;
+
+use crate::span::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    Public,
+    PubCrate,
+    Private,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GenericParamKind {
+    Lifetime,
+    Type,
+    Const,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GenericParam {
+    pub name: String,
+    pub kind: GenericParamKind,
+    /// Trait/lifetime bounds attached inline (`T: Clone + Default`), in
+    /// source order. Bounds introduced in a trailing `where` clause are
+    /// folded in here as well so callers see the full constraint set.
+    pub bounds: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemKind {
+    Struct,
+    Enum,
+    EnumVariant,
+    Field,
+    Trait,
+    Impl,
+    Fn,
+    Method,
+    AssocType,
+    Mod,
+    Const,
+    Static,
+    TypeAlias,
+    MacroRules,
+    Use,
+    /// A region the parser could not make sense of. Produced only when the
+    /// parser recovers from a syntax error; carries no useful `name`/`path`
+    /// beyond `<error>`.
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub name: String,
+    /// `::`-joined path from the file root down to this item, e.g.
+    /// `internal::COUNTER` or `TypedMap::insert`.
+    pub path: String,
+    pub visibility: Visibility,
+    pub generics: Vec<GenericParam>,
+    /// Raw bound text contributed purely by a trailing `where` clause
+    /// (e.g. `K: Hash + Eq`), kept alongside `generics` for callers that
+    /// want to distinguish inline bounds from `where`-clause bounds.
+    pub where_bounds: Vec<String>,
+    pub doc: Option<String>,
+    pub span: Span,
+    pub children: Vec<Item>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParsedFile {
+    pub path: Option<String>,
+    pub items: Vec<Item>,
+    /// Recovered-from syntax problems, in source order. Empty for a
+    /// well-formed file.
+    pub diagnostics: Vec<Diagnostic>,
+}
@@ -0,0 +1,243 @@
+//! Cross-checks the Doxygen-style tags (`@param`, `@tparam`, `@return`,
+//! `@safety`) used throughout the fixtures against the signature of the
+//! item they document, so a function whose doc comment has drifted from
+//! its real parameter list or return type shows up as a diagnostic
+//! instead of silently rotting.
+
+use crate::ast::{Diagnostic, Item, ItemKind, ParsedFile};
+use crate::lexer::{lex, Token, TokenKind};
+use crate::token_util::{is_ident, is_punct, skip_angle_balanced, skip_balanced};
+
+/// Runs the doc-completeness pass over every function/method in `parsed`,
+/// whose source text is `src` (needed to recover real parameter names and
+/// return types, which the item tree only tracks as a byte span).
+pub fn check(parsed: &ParsedFile, src: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for item in &parsed.items {
+        check_item(item, src, &mut diagnostics);
+    }
+    diagnostics
+}
+
+fn check_item(item: &Item, src: &str, out: &mut Vec<Diagnostic>) {
+    if matches!(item.kind, ItemKind::Fn | ItemKind::Method) {
+        check_fn(item, src, out);
+    }
+    // `@tparam` applies to anything that can carry its own generic
+    // parameters (struct/enum/trait/impl), not just fns/methods, which
+    // `check_fn` above already covers.
+    if !matches!(item.kind, ItemKind::Fn | ItemKind::Method) {
+        check_tparams(item, item.doc.as_deref().unwrap_or(""), out);
+    }
+    for child in &item.children {
+        check_item(child, src, out);
+    }
+}
+
+struct Tags {
+    params: Vec<String>,
+    tparams: Vec<String>,
+    has_return: bool,
+    has_safety: bool,
+}
+
+fn parse_tags(doc: &str) -> Tags {
+    let mut tags = Tags {
+        params: Vec::new(),
+        tparams: Vec::new(),
+        has_return: false,
+        has_safety: false,
+    };
+    for line in doc.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("@param ") {
+            if let Some(name) = rest.split_whitespace().next() {
+                tags.params.push(name.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("@tparam ") {
+            if let Some(name) = rest.split_whitespace().next() {
+                tags.tparams.push(name.trim_start_matches('\'').to_string());
+            }
+        } else if line.starts_with("@return") {
+            tags.has_return = true;
+        } else if line.starts_with("@safety") {
+            tags.has_safety = true;
+        }
+    }
+    tags
+}
+
+/// Cross-checks `@tparam` tags in `doc` against `item`'s own `generics` —
+/// shared by fns/methods (via [`check_fn`]) and by struct/enum/trait/impl
+/// items, all of which can declare generic parameters.
+fn check_tparams(item: &Item, doc: &str, out: &mut Vec<Diagnostic>) {
+    for declared in &parse_tags(doc).tparams {
+        if !item.generics.iter().any(|g| &g.name == declared) {
+            out.push(Diagnostic {
+                message: format!(
+                    "@tparam {declared} on `{}` does not match any generic parameter",
+                    item.path
+                ),
+                span: item.span,
+            });
+        }
+    }
+}
+
+struct Signature {
+    params: Vec<String>,
+    returns_value: bool,
+    is_unsafe: bool,
+}
+
+/// Extracts parameter names from the tokens strictly between a function's
+/// `(` and `)`, ignoring the `self`/`&self`/`&mut self` receiver.
+fn extract_param_names(tokens: &[Token]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let seg_start = i;
+        let mut depth = 0i32;
+        while i < tokens.len() {
+            match &tokens[i].kind {
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Punct(p) if p == "," && depth == 0 => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        let seg = &tokens[seg_start..i];
+        let significant: Vec<&str> = seg
+            .iter()
+            .filter_map(|t| match &t.kind {
+                TokenKind::Ident(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        // A parameter is `name: Type`, optionally preceded by `mut`; the
+        // receiver is bare `self`/`mut self` with no following `:`.
+        match significant.as_slice() {
+            ["self", ..] | ["mut", "self", ..] => {}
+            ["mut", name, ..] => names.push(name.to_string()),
+            [name, ..] => names.push(name.to_string()),
+            [] => {}
+        }
+        if i < tokens.len() {
+            i += 1; // skip comma
+        }
+    }
+    names
+}
+
+fn analyze_signature(text: &str) -> Signature {
+    let tokens = lex(text);
+    let mut i = 0;
+    let mut is_unsafe = false;
+
+    if is_ident(&tokens[i].kind, "pub") {
+        i += 1;
+        if is_punct(&tokens[i].kind, "(") {
+            i = skip_balanced(&tokens, i);
+        }
+    }
+    loop {
+        if is_ident(&tokens[i].kind, "unsafe") {
+            is_unsafe = true;
+            i += 1;
+        } else if is_ident(&tokens[i].kind, "async") || is_ident(&tokens[i].kind, "const") {
+            i += 1;
+        } else if is_ident(&tokens[i].kind, "extern") {
+            i += 1;
+            if matches!(tokens[i].kind, TokenKind::StringLit(_)) {
+                i += 1;
+            }
+        } else {
+            break;
+        }
+    }
+    // tokens[i] is "fn"; tokens[i + 1] is the function name.
+    i += 2;
+    if is_punct(&tokens[i].kind, "<") {
+        i = skip_angle_balanced(&tokens, i);
+    }
+    let mut params = Vec::new();
+    if is_punct(&tokens[i].kind, "(") {
+        let close = skip_balanced(&tokens, i);
+        params = extract_param_names(&tokens[i + 1..close - 1]);
+        i = close;
+    }
+    let returns_value = if is_punct(&tokens[i].kind, "->") {
+        i += 1;
+        let ret_start = i;
+        let mut depth = 0i32;
+        while i < tokens.len() {
+            match &tokens[i].kind {
+                TokenKind::Punct(p) if p == "{" && depth == 0 => break,
+                TokenKind::Punct(p) if p == ";" && depth == 0 => break,
+                TokenKind::Ident(x) if x == "where" && depth == 0 => break,
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Eof => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        let start_off = tokens[ret_start].span.start;
+        let end_off = tokens[i.saturating_sub(1).max(ret_start)].span.end;
+        let ret_text = text[start_off..end_off].trim();
+        !ret_text.is_empty() && ret_text != "()"
+    } else {
+        false
+    };
+    Signature {
+        params,
+        returns_value,
+        is_unsafe,
+    }
+}
+
+fn check_fn(item: &Item, src: &str, out: &mut Vec<Diagnostic>) {
+    let signature = analyze_signature(&src[item.span.start..item.span.end]);
+    let doc = item.doc.as_deref().unwrap_or("");
+    let tags = parse_tags(doc);
+
+    for declared in &tags.params {
+        if !signature.params.contains(declared) {
+            out.push(Diagnostic {
+                message: format!("@param {declared} on `{}` does not match any parameter", item.path),
+                span: item.span,
+            });
+        }
+    }
+    for actual in &signature.params {
+        if !tags.params.contains(actual) {
+            out.push(Diagnostic {
+                message: format!("parameter `{actual}` on `{}` has no @param tag", item.path),
+                span: item.span,
+            });
+        }
+    }
+
+    check_tparams(item, doc, out);
+
+    if signature.returns_value && !tags.has_return {
+        out.push(Diagnostic {
+            message: format!("`{}` returns a value but has no @return tag", item.path),
+            span: item.span,
+        });
+    }
+    if !signature.returns_value && tags.has_return {
+        out.push(Diagnostic {
+            message: format!("`{}` has an @return tag but returns `()`", item.path),
+            span: item.span,
+        });
+    }
+
+    if signature.is_unsafe && !tags.has_safety {
+        out.push(Diagnostic {
+            message: format!("unsafe fn `{}` has no @safety tag", item.path),
+            span: item.span,
+        });
+    }
+}
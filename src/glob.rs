@@ -0,0 +1,207 @@
+//! A small glob matcher supporting `*` (within one path segment), `**`
+//! (across segments), `?`, and `[...]` character classes, plus a directory
+//! walk that compiles a pattern into per-segment sub-patterns and prunes
+//! branches that cannot match — a segment with no wildcard characters is
+//! looked up directly instead of scanning its parent directory's entries.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GlobToken {
+    Literal(char),
+    Star,
+    Question,
+    Class { negate: bool, ranges: Vec<(char, char)> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// No wildcard characters; can be looked up directly.
+    Literal(String),
+    /// `**`: matches zero or more path components.
+    DoubleStar,
+    Pattern(Vec<GlobToken>),
+}
+
+/// A compiled glob pattern, e.g. `src/**/*.rs`.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    segments: Vec<Segment>,
+}
+
+impl Glob {
+    pub fn compile(pattern: &str) -> Self {
+        let segments = pattern
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(compile_segment)
+            .collect();
+        Self { segments }
+    }
+
+    /// Walks `root` and returns every regular file matching this pattern,
+    /// in directory-traversal order.
+    pub fn walk(&self, root: &Path) -> Vec<PathBuf> {
+        let mut out = Vec::new();
+        walk_segments(root, &self.segments, &mut out);
+        out
+    }
+}
+
+fn compile_segment(raw: &str) -> Segment {
+    if raw == "**" {
+        return Segment::DoubleStar;
+    }
+    let tokens = tokenize_segment(raw);
+    if tokens.iter().all(|t| matches!(t, GlobToken::Literal(_))) {
+        let literal: String = tokens
+            .iter()
+            .map(|t| match t {
+                GlobToken::Literal(c) => *c,
+                _ => unreachable!(),
+            })
+            .collect();
+        Segment::Literal(literal)
+    } else {
+        Segment::Pattern(tokens)
+    }
+}
+
+fn tokenize_segment(raw: &str) -> Vec<GlobToken> {
+    let chars: Vec<char> = raw.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' => {
+                tokens.push(GlobToken::Star);
+                i += 1;
+            }
+            '?' => {
+                tokens.push(GlobToken::Question);
+                i += 1;
+            }
+            '[' => {
+                let mut j = i + 1;
+                let negate = matches!(chars.get(j), Some('!') | Some('^'));
+                if negate {
+                    j += 1;
+                }
+                let class_start = j;
+                while j < chars.len() && chars[j] != ']' {
+                    j += 1;
+                }
+                let mut ranges = Vec::new();
+                let class: Vec<char> = chars[class_start..j.min(chars.len())].to_vec();
+                let mut k = 0;
+                while k < class.len() {
+                    if k + 2 < class.len() && class[k + 1] == '-' {
+                        ranges.push((class[k], class[k + 2]));
+                        k += 3;
+                    } else {
+                        ranges.push((class[k], class[k]));
+                        k += 1;
+                    }
+                }
+                tokens.push(GlobToken::Class { negate, ranges });
+                i = if j < chars.len() { j + 1 } else { j };
+            }
+            c => {
+                tokens.push(GlobToken::Literal(c));
+                i += 1;
+            }
+        }
+    }
+    tokens
+}
+
+fn token_matches(tokens: &[GlobToken], name: &[char]) -> bool {
+    match tokens.first() {
+        None => name.is_empty(),
+        Some(GlobToken::Literal(c)) => {
+            matches!(name.first(), Some(n) if n == c) && token_matches(&tokens[1..], &name[1..])
+        }
+        Some(GlobToken::Question) => !name.is_empty() && token_matches(&tokens[1..], &name[1..]),
+        Some(GlobToken::Star) => {
+            for i in 0..=name.len() {
+                if token_matches(&tokens[1..], &name[i..]) {
+                    return true;
+                }
+            }
+            false
+        }
+        Some(GlobToken::Class { negate, ranges }) => match name.first() {
+            None => false,
+            Some(&c) => {
+                let in_class = ranges.iter().any(|&(a, b)| c >= a && c <= b);
+                (in_class != *negate) && token_matches(&tokens[1..], &name[1..])
+            }
+        },
+    }
+}
+
+fn segment_matches(segment: &Segment, name: &str) -> bool {
+    match segment {
+        Segment::Literal(lit) => lit == name,
+        Segment::DoubleStar => true,
+        Segment::Pattern(tokens) => {
+            let chars: Vec<char> = name.chars().collect();
+            token_matches(tokens, &chars)
+        }
+    }
+}
+
+fn walk_segments(dir: &Path, segments: &[Segment], out: &mut Vec<PathBuf>) {
+    let Some((first, rest)) = segments.split_first() else {
+        return;
+    };
+
+    if matches!(first, Segment::DoubleStar) {
+        // `**` matches zero directories (try the rest right here)...
+        walk_segments(dir, rest, out);
+        // ...or one-or-more, by recursing into every subdirectory while
+        // keeping `**` in play.
+        if let Ok(entries) = fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                if entry.path().is_dir() {
+                    walk_segments(&entry.path(), segments, out);
+                }
+            }
+        }
+        return;
+    }
+
+    // A fixed (non-wildcard) segment is looked up directly rather than
+    // scanning the whole directory, pruning unrelated siblings.
+    if let Segment::Literal(name) = first {
+        let candidate = dir.join(name);
+        if rest.is_empty() {
+            if candidate.is_file() {
+                out.push(candidate);
+            }
+        } else if candidate.is_dir() {
+            walk_segments(&candidate, rest, out);
+        }
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let Some(name) = name.to_str() else { continue };
+        if !segment_matches(first, name) {
+            continue;
+        }
+        let path = entry.path();
+        if rest.is_empty() {
+            if path.is_file() {
+                out.push(path);
+            }
+        } else if path.is_dir() {
+            walk_segments(&path, rest, out);
+        }
+    }
+}
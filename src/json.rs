@@ -0,0 +1,76 @@
+//! Newline-delimited JSON emission for a [`ParsedFile`], in the spirit of
+//! rustc's `--error-format=json`: each declaration becomes one
+//! self-describing JSON object on its own line, so downstream tools can
+//! stream-consume the symbol index without scraping pretty-printed text.
+
+use serde::Serialize;
+
+use crate::ast::{Diagnostic, GenericParam, Item, ItemKind, ParsedFile, Visibility};
+use crate::span::Span;
+
+#[derive(Serialize)]
+#[serde(tag = "record_type", rename_all = "snake_case")]
+enum Record<'a> {
+    Item(ItemRecord<'a>),
+    Diagnostic(DiagnosticRecord<'a>),
+}
+
+#[derive(Serialize)]
+struct ItemRecord<'a> {
+    kind: &'a ItemKind,
+    name: &'a str,
+    path: &'a str,
+    parent: Option<&'a str>,
+    visibility: &'a Visibility,
+    generics: &'a [GenericParam],
+    where_bounds: &'a [String],
+    doc: Option<&'a str>,
+    span: Span,
+}
+
+#[derive(Serialize)]
+struct DiagnosticRecord<'a> {
+    message: &'a str,
+    span: Span,
+}
+
+impl ParsedFile {
+    /// Renders every item (and nested field/variant/method/etc.), followed
+    /// by every recovered diagnostic, as one JSON object per line.
+    pub fn to_json(&self) -> String {
+        let mut lines = Vec::new();
+        for item in &self.items {
+            collect(item, None, &mut lines);
+        }
+        for diagnostic in &self.diagnostics {
+            lines.push(diagnostic_line(diagnostic));
+        }
+        lines.join("\n")
+    }
+}
+
+fn collect<'a>(item: &'a Item, parent: Option<&'a str>, out: &mut Vec<String>) {
+    let record = Record::Item(ItemRecord {
+        kind: &item.kind,
+        name: &item.name,
+        path: &item.path,
+        parent,
+        visibility: &item.visibility,
+        generics: &item.generics,
+        where_bounds: &item.where_bounds,
+        doc: item.doc.as_deref(),
+        span: item.span,
+    });
+    out.push(serde_json::to_string(&record).expect("item record is always valid JSON"));
+    for child in &item.children {
+        collect(child, Some(item.path.as_str()), out);
+    }
+}
+
+fn diagnostic_line(diagnostic: &Diagnostic) -> String {
+    let record = Record::Diagnostic(DiagnosticRecord {
+        message: &diagnostic.message,
+        span: diagnostic.span,
+    });
+    serde_json::to_string(&record).expect("diagnostic record is always valid JSON")
+}
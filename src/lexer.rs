@@ -0,0 +1,332 @@
+//! A hand-rolled lexer covering the subset of Rust syntax exercised by the
+//! fixtures under `tests/fixtures/`: items, generics, doc comments, string
+//! and char literals (including raw strings), and the common multi-char
+//! operators. It does not need to tokenize arbitrary expression bodies in
+//! detail since the parser only skips over them by brace-depth.
+
+use crate::span::Span;
+
+/// Longest-match-first list of multi-character punctuation the parser cares
+/// about. Anything else falls back to a single-character `Punct`.
+const MULTI_PUNCT: &[&str] = &[
+    "..=", "...", "::", "->", "=>", "..", "&&", "||", "==", "!=", "<=", ">=", "+=", "-=",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    Ident(String),
+    Lifetime(String),
+    Punct(String),
+    StringLit(String),
+    CharLit(String),
+    Number(String),
+    DocComment { text: String, inner: bool },
+    Comment,
+    Eof,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+/// Tokenize `src`, dropping plain whitespace but keeping comments (including
+/// doc comments, which the parser attaches to the following item).
+pub fn lex(src: &str) -> Vec<Token> {
+    let bytes = src.as_bytes();
+    let len = bytes.len();
+    let mut pos = 0usize;
+    let mut out = Vec::new();
+
+    let chars: Vec<(usize, char)> = src.char_indices().collect();
+    let mut idx = 0usize;
+
+    let byte_at = |i: usize| -> Option<char> {
+        if i < chars.len() {
+            Some(chars[i].1)
+        } else {
+            None
+        }
+    };
+    let offset_at = |i: usize| -> usize {
+        if i < chars.len() {
+            chars[i].0
+        } else {
+            len
+        }
+    };
+
+    while idx < chars.len() {
+        let (start_off, c) = chars[idx];
+        if c.is_whitespace() {
+            idx += 1;
+            continue;
+        }
+
+        // Comments / doc comments.
+        if c == '/' && byte_at(idx + 1) == Some('/') {
+            let is_doc_outer = byte_at(idx + 2) == Some('/') && byte_at(idx + 3) != Some('/');
+            let is_doc_inner = byte_at(idx + 2) == Some('!');
+            let mut j = idx;
+            while j < chars.len() && chars[j].1 != '\n' {
+                j += 1;
+            }
+            let end_off = offset_at(j);
+            if is_doc_outer || is_doc_inner {
+                let skip = if is_doc_outer { 3 } else { 2 };
+                let text_start = offset_at(idx + skip);
+                let text = src[text_start..end_off].trim().to_string();
+                out.push(Token {
+                    kind: TokenKind::DocComment {
+                        text,
+                        inner: is_doc_inner,
+                    },
+                    span: Span::new(start_off, end_off),
+                });
+            } else {
+                out.push(Token {
+                    kind: TokenKind::Comment,
+                    span: Span::new(start_off, end_off),
+                });
+            }
+            idx = j;
+            continue;
+        }
+        if c == '/' && byte_at(idx + 1) == Some('*') {
+            let is_doc_outer = byte_at(idx + 2) == Some('*') && byte_at(idx + 3) != Some('*') && byte_at(idx + 3) != Some('/');
+            let is_doc_inner = byte_at(idx + 2) == Some('!');
+            let mut j = idx + 2;
+            let mut depth = 1i32;
+            while j < chars.len() && depth > 0 {
+                if chars[j].1 == '/' && byte_at(j + 1) == Some('*') {
+                    depth += 1;
+                    j += 2;
+                } else if chars[j].1 == '*' && byte_at(j + 1) == Some('/') {
+                    depth -= 1;
+                    j += 2;
+                } else {
+                    j += 1;
+                }
+            }
+            let end_off = offset_at(j);
+            if is_doc_outer || is_doc_inner {
+                let skip = if is_doc_outer { 3 } else { 2 };
+                let text_start_idx = idx + skip;
+                let text_end_idx = j.saturating_sub(2);
+                let text_start = offset_at(text_start_idx.min(chars.len()));
+                let text_end = offset_at(text_end_idx.max(text_start_idx).min(chars.len()));
+                let text = if text_end > text_start {
+                    src[text_start..text_end].trim().to_string()
+                } else {
+                    String::new()
+                };
+                out.push(Token {
+                    kind: TokenKind::DocComment {
+                        text,
+                        inner: is_doc_inner,
+                    },
+                    span: Span::new(start_off, end_off),
+                });
+            } else {
+                out.push(Token {
+                    kind: TokenKind::Comment,
+                    span: Span::new(start_off, end_off),
+                });
+            }
+            idx = j;
+            continue;
+        }
+
+        // Raw strings: r"...", r#"..."#, br"...", etc.
+        if c == 'r' || (c == 'b' && byte_at(idx + 1) == Some('r')) {
+            if let Some(end_idx) = try_lex_raw_string(&chars, idx) {
+                let end_off = offset_at(end_idx);
+                out.push(Token {
+                    kind: TokenKind::StringLit(src[offset_at(idx)..end_off].to_string()),
+                    span: Span::new(start_off, end_off),
+                });
+                idx = end_idx;
+                continue;
+            }
+        }
+
+        // Normal string literal.
+        if c == '"' {
+            let mut j = idx + 1;
+            while j < chars.len() {
+                if chars[j].1 == '\\' {
+                    j += 2;
+                    continue;
+                }
+                if chars[j].1 == '"' {
+                    j += 1;
+                    break;
+                }
+                j += 1;
+            }
+            let end_off = offset_at(j);
+            out.push(Token {
+                kind: TokenKind::StringLit(src[offset_at(idx)..end_off].to_string()),
+                span: Span::new(start_off, end_off),
+            });
+            idx = j;
+            continue;
+        }
+
+        // Char literal or lifetime.
+        if c == '\'' {
+            // Lifetime: 'ident not immediately followed by a closing quote.
+            if let Some(next) = byte_at(idx + 1) {
+                if is_ident_start(next) {
+                    let mut j = idx + 1;
+                    while j < chars.len() && is_ident_continue(chars[j].1) {
+                        j += 1;
+                    }
+                    if byte_at(j) != Some('\'') {
+                        let name = src[offset_at(idx + 1)..offset_at(j)].to_string();
+                        let end_off = offset_at(j);
+                        out.push(Token {
+                            kind: TokenKind::Lifetime(name),
+                            span: Span::new(start_off, end_off),
+                        });
+                        idx = j;
+                        continue;
+                    }
+                }
+            }
+            let mut j = idx + 1;
+            if byte_at(j) == Some('\\') {
+                j += 2;
+            } else {
+                j += 1;
+            }
+            if byte_at(j) == Some('\'') {
+                j += 1;
+            }
+            let end_off = offset_at(j);
+            out.push(Token {
+                kind: TokenKind::CharLit(src[offset_at(idx)..end_off].to_string()),
+                span: Span::new(start_off, end_off),
+            });
+            idx = j;
+            continue;
+        }
+
+        // Numbers.
+        if c.is_ascii_digit() {
+            let mut j = idx;
+            while j < chars.len() && (chars[j].1.is_alphanumeric() || chars[j].1 == '_' || chars[j].1 == '.') {
+                j += 1;
+            }
+            let end_off = offset_at(j);
+            out.push(Token {
+                kind: TokenKind::Number(src[offset_at(idx)..end_off].to_string()),
+                span: Span::new(start_off, end_off),
+            });
+            idx = j;
+            continue;
+        }
+
+        // Identifiers / keywords.
+        if is_ident_start(c) {
+            let mut j = idx;
+            while j < chars.len() && is_ident_continue(chars[j].1) {
+                j += 1;
+            }
+            let end_off = offset_at(j);
+            out.push(Token {
+                kind: TokenKind::Ident(src[offset_at(idx)..end_off].to_string()),
+                span: Span::new(start_off, end_off),
+            });
+            idx = j;
+            continue;
+        }
+
+        // Multi-char punctuation, longest match first.
+        let remaining: String = chars[idx..].iter().take(3).map(|&(_, ch)| ch).collect();
+        let mut matched = None;
+        for p in MULTI_PUNCT {
+            if remaining.starts_with(p) {
+                matched = Some(*p);
+                break;
+            }
+        }
+        if let Some(p) = matched {
+            let j = idx + p.chars().count();
+            let end_off = offset_at(j);
+            out.push(Token {
+                kind: TokenKind::Punct(p.to_string()),
+                span: Span::new(start_off, end_off),
+            });
+            idx = j;
+            continue;
+        }
+
+        // Single-char punctuation.
+        let end_off = offset_at(idx + 1);
+        out.push(Token {
+            kind: TokenKind::Punct(c.to_string()),
+            span: Span::new(start_off, end_off),
+        });
+        idx += 1;
+    }
+
+    let eof_off = pos.max(len);
+    out.push(Token {
+        kind: TokenKind::Eof,
+        span: Span::new(eof_off, eof_off),
+    });
+    pos = len;
+    let _ = pos;
+    out
+}
+
+/// Attempts to lex a raw string literal (optionally byte-prefixed) starting
+/// at `idx`. Returns the token-end index on success, or `None` if `idx`
+/// doesn't actually start one (so the caller can fall through).
+fn try_lex_raw_string(chars: &[(usize, char)], idx: usize) -> Option<usize> {
+    let mut j = idx;
+    if chars[j].1 == 'b' {
+        j += 1;
+    }
+    if chars.get(j).map(|&(_, c)| c) != Some('r') {
+        return None;
+    }
+    j += 1;
+    let mut hashes = 0usize;
+    while chars.get(j).map(|&(_, c)| c) == Some('#') {
+        hashes += 1;
+        j += 1;
+    }
+    if chars.get(j).map(|&(_, c)| c) != Some('"') {
+        return None;
+    }
+    j += 1;
+    loop {
+        match chars.get(j) {
+            None => return Some(j),
+            Some(&(_, '"')) => {
+                let mut k = j + 1;
+                let mut matched_hashes = 0usize;
+                while matched_hashes < hashes && chars.get(k).map(|&(_, c)| c) == Some('#') {
+                    matched_hashes += 1;
+                    k += 1;
+                }
+                if matched_hashes == hashes {
+                    return Some(k);
+                }
+                j += 1;
+            }
+            Some(_) => j += 1,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Structural parsing and tooling for the Rust fixtures in `tests/fixtures/`.
+
+pub mod ast;
+pub mod doc_lint;
+pub mod glob;
+pub mod json;
+pub mod lexer;
+pub mod parser;
+pub mod pretty;
+pub mod span;
+mod token_util;
+pub mod workspace;
+
+pub use ast::ParsedFile;
+pub use parser::parse;
+pub use workspace::Workspace;
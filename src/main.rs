@@ -0,0 +1,146 @@
+use std::path::Path;
+use std::{fs, process::ExitCode};
+
+use usereq::{doc_lint, parse, Workspace};
+
+fn print_usage() {
+    eprintln!("usage: usereq <file> [--emit json] [--check-doc]");
+    eprintln!("       usereq --glob <root> <pattern> [--emit json | --imports <symbol> | --resolve <path>]");
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("--glob") {
+        return run_glob(&args[1..]);
+    }
+    run_single_file(&args)
+}
+
+fn run_single_file(args: &[String]) -> ExitCode {
+    let mut path = None;
+    let mut emit_json = false;
+    let mut check_doc = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--emit" => {
+                i += 1;
+                match args.get(i).map(String::as_str) {
+                    Some("json") => emit_json = true,
+                    other => {
+                        eprintln!("unknown --emit target: {:?}", other);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            "--check-doc" => check_doc = true,
+            other if path.is_none() => path = Some(other.to_string()),
+            other => {
+                eprintln!("unexpected argument: {other}");
+                print_usage();
+                return ExitCode::FAILURE;
+            }
+        }
+        i += 1;
+    }
+
+    let Some(path) = path else {
+        print_usage();
+        return ExitCode::FAILURE;
+    };
+
+    let src = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("failed to read {path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut parsed = parse(src.clone());
+    parsed.path = Some(path);
+
+    if emit_json {
+        println!("{}", parsed.to_json());
+    } else {
+        for item in &parsed.items {
+            println!("{:?} {}", item.kind, item.path);
+        }
+    }
+
+    for diagnostic in &parsed.diagnostics {
+        eprintln!(
+            "warning: {} ({}..{})",
+            diagnostic.message, diagnostic.span.start, diagnostic.span.end
+        );
+    }
+
+    if check_doc {
+        let mut ok = true;
+        for diagnostic in doc_lint::check(&parsed, &src) {
+            ok = false;
+            eprintln!(
+                "doc: {} ({}..{})",
+                diagnostic.message, diagnostic.span.start, diagnostic.span.end
+            );
+        }
+        if !ok {
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run_glob(args: &[String]) -> ExitCode {
+    let Some([root, pattern]) = args.get(0..2) else {
+        print_usage();
+        return ExitCode::FAILURE;
+    };
+    let workspace = Workspace::from_glob(Path::new(root), pattern);
+
+    match args.get(2).map(String::as_str) {
+        Some("--imports") => {
+            let Some(symbol) = args.get(3) else {
+                eprintln!("--imports requires a symbol name");
+                return ExitCode::FAILURE;
+            };
+            for path in workspace.files_importing(symbol) {
+                println!("{}", path.display());
+            }
+        }
+        Some("--resolve") => {
+            let Some(target) = args.get(3) else {
+                eprintln!("--resolve requires a path");
+                return ExitCode::FAILURE;
+            };
+            match workspace.resolve(target) {
+                Some(item) => println!("{:?} {}", item.kind, item.path),
+                None => {
+                    eprintln!("could not resolve {target}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Some("--emit") if args.get(3).map(String::as_str) == Some("json") => {
+            for file in &workspace.files {
+                println!("{}", file.parsed.to_json());
+            }
+        }
+        None => {
+            for file in &workspace.files {
+                println!("== {}", file.path.display());
+                for item in &file.parsed.items {
+                    println!("{:?} {}", item.kind, item.path);
+                }
+            }
+        }
+        _ => {
+            print_usage();
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
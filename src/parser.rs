@@ -0,0 +1,1020 @@
+//! Recursive-descent parser over items. It does not build a full
+//! expression AST: function/const/static bodies and initializers are
+//! skipped by tracking delimiter balance, since the symbol index only
+//! needs to know where a declaration starts and ends, not what it computes.
+
+use crate::ast::*;
+use crate::lexer::{lex, Token, TokenKind};
+use crate::span::Span;
+
+pub struct Parser {
+    src: String,
+    tokens: Vec<Token>,
+    pos: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Parser {
+    pub fn new(src: impl Into<String>) -> Self {
+        let src = src.into();
+        let tokens = lex(&src);
+        Self {
+            src,
+            tokens,
+            pos: 0,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn parse_file(mut self) -> ParsedFile {
+        let items = self.parse_items("", false);
+        ParsedFile {
+            path: None,
+            items,
+            diagnostics: self.diagnostics,
+        }
+    }
+
+    // -- low-level token helpers -------------------------------------------------
+
+    fn kind(&self, i: usize) -> &TokenKind {
+        &self.tokens[i.min(self.tokens.len() - 1)].kind
+    }
+
+    fn cur(&self) -> &TokenKind {
+        self.kind(self.pos)
+    }
+
+    fn is_eof(&self) -> bool {
+        matches!(self.cur(), TokenKind::Eof)
+    }
+
+    fn is_ident(&self, s: &str) -> bool {
+        matches!(self.cur(), TokenKind::Ident(x) if x == s)
+    }
+
+    fn is_punct(&self, s: &str) -> bool {
+        matches!(self.cur(), TokenKind::Punct(x) if x == s)
+    }
+
+    fn bump(&mut self) -> TokenKind {
+        let k = self.tokens[self.pos].kind.clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        k
+    }
+
+    fn text_between(&self, start_tok: usize, end_tok_exclusive: usize) -> String {
+        if end_tok_exclusive <= start_tok {
+            return String::new();
+        }
+        let start = self.tokens[start_tok].span.start;
+        let end = self.tokens[end_tok_exclusive - 1].span.end;
+        self.src[start..end].trim().to_string()
+    }
+
+    /// Skips a balanced bracketed region starting at an opening
+    /// `(` / `[` / `{` token, returning the index just past the matching
+    /// close.
+    fn skip_balanced(&self, open_idx: usize) -> usize {
+        crate::token_util::skip_balanced(&self.tokens, open_idx)
+    }
+
+    /// Skips a `<...>` generic argument/parameter list starting at the
+    /// opening `<`, returning the index just past the matching `>`.
+    /// Only valid in item-header position, where `<`/`>` cannot be
+    /// comparison/shift operators.
+    fn skip_angle_balanced(&self, open_idx: usize) -> usize {
+        crate::token_util::skip_angle_balanced(&self.tokens, open_idx)
+    }
+
+    /// Advances past tokens until one at depth 0 matches `stop`, without
+    /// consuming it. `depth` tracks `(`, `[`, `{`, `<` nesting so commas/colons
+    /// inside nested brackets aren't mistaken for top-level separators.
+    /// Bumps tokens until one of `stop` is reached at depth 0, tracking
+    /// `(`/`[`/`{` *and* `<`/`>` depth. Every call site skips a type or
+    /// bound in item-header position, where `<`/`>` can only be a generic
+    /// argument list, never a comparison/shift operator — so a comma or
+    /// stop token nested inside `HashMap<K, V>` doesn't end the skip early.
+    /// `stop` entries are matched against both punctuation and identifier
+    /// tokens, since a return-type scan needs to stop at the `where`
+    /// keyword the same way it stops at `{` or `;`.
+    fn advance_until_top_level(&mut self, stop: &[&str]) {
+        let mut depth = 0i32;
+        loop {
+            match self.cur() {
+                TokenKind::Eof => return,
+                TokenKind::Punct(p) if depth == 0 && stop.iter().any(|s| s == p) => return,
+                TokenKind::Ident(x) if depth == 0 && stop.iter().any(|s| s == x) => return,
+                TokenKind::Punct(p) if p == "(" || p == "[" || p == "{" || p == "<" => {
+                    depth += 1;
+                    self.bump();
+                }
+                TokenKind::Punct(p) if p == ")" || p == "]" || p == "}" || p == ">" => {
+                    depth -= 1;
+                    self.bump();
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    fn join_path(parent: &str, name: &str) -> String {
+        if parent.is_empty() {
+            name.to_string()
+        } else {
+            format!("{parent}::{name}")
+        }
+    }
+
+    // -- trivia / visibility / attributes ----------------------------------------
+
+    fn collect_doc_and_skip_trivia(&mut self) -> Option<String> {
+        let mut doc_lines = Vec::new();
+        loop {
+            match self.cur() {
+                TokenKind::DocComment { text, inner: false } => {
+                    doc_lines.push(text.clone());
+                    self.bump();
+                }
+                TokenKind::DocComment { inner: true, .. } | TokenKind::Comment => {
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+        if doc_lines.is_empty() {
+            None
+        } else {
+            Some(doc_lines.join("\n"))
+        }
+    }
+
+    fn skip_attributes(&mut self) {
+        loop {
+            self.collect_doc_and_skip_trivia();
+            if self.is_punct("#") {
+                self.bump();
+                if self.is_punct("!") {
+                    self.bump();
+                }
+                if self.is_punct("[") {
+                    let end = self.skip_balanced(self.pos);
+                    self.pos = end;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_visibility(&mut self) -> Visibility {
+        if self.is_ident("pub") {
+            self.bump();
+            if self.is_punct("(") {
+                let restriction = if let TokenKind::Ident(name) = self.kind(self.pos + 1) {
+                    name.clone()
+                } else {
+                    String::new()
+                };
+                let end = self.skip_balanced(self.pos);
+                self.pos = end;
+                if restriction == "crate" {
+                    return Visibility::PubCrate;
+                }
+            }
+            Visibility::Public
+        } else {
+            Visibility::Private
+        }
+    }
+
+    // -- generics / where clauses -------------------------------------------------
+
+    /// Parses an optional `<...>` generic parameter list at the current
+    /// position. Lifetime/type/const params are split on top-level commas;
+    /// inline bounds (`T: Clone + Default`) are captured immediately.
+    fn parse_generics(&mut self) -> Vec<GenericParam> {
+        if !self.is_punct("<") {
+            return Vec::new();
+        }
+        let open = self.pos;
+        let close = self.skip_angle_balanced(open);
+        let mut params = Vec::new();
+        let mut i = open + 1;
+        let end = close - 1; // index of the closing '>'
+        while i < end {
+            let seg_start = i;
+            let mut depth = 0i32;
+            while i < end {
+                match self.kind(i) {
+                    TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                    TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                    TokenKind::Punct(p) if p == "," && depth == 0 => break,
+                    _ => {}
+                }
+                i += 1;
+            }
+            params.push(self.parse_one_generic_param(seg_start, i));
+            if i < end {
+                i += 1; // skip comma
+            }
+        }
+        self.pos = close;
+        params
+    }
+
+    fn parse_one_generic_param(&self, start: usize, end: usize) -> GenericParam {
+        if matches!(self.kind(start), TokenKind::Lifetime(_)) {
+            let name = if let TokenKind::Lifetime(n) = self.kind(start) {
+                n.clone()
+            } else {
+                unreachable!()
+            };
+            let bounds = self.split_bounds_after_colon(start + 1, end);
+            return GenericParam {
+                name,
+                kind: GenericParamKind::Lifetime,
+                bounds,
+            };
+        }
+        if matches!(self.kind(start), TokenKind::Ident(x) if x == "const") {
+            let name_idx = start + 1;
+            let name = if let TokenKind::Ident(n) = self.kind(name_idx) {
+                n.clone()
+            } else {
+                String::new()
+            };
+            return GenericParam {
+                name,
+                kind: GenericParamKind::Const,
+                bounds: Vec::new(),
+            };
+        }
+        let name = if let TokenKind::Ident(n) = self.kind(start) {
+            n.clone()
+        } else {
+            self.text_between(start, start + 1)
+        };
+        let bounds = self.split_bounds_after_colon(start + 1, end);
+        GenericParam {
+            name,
+            kind: GenericParamKind::Type,
+            bounds,
+        }
+    }
+
+    /// Given the token range right after a generic param's name, finds an
+    /// optional `: bound + bound` suffix and splits it on top-level `+`.
+    fn split_bounds_after_colon(&self, start: usize, end: usize) -> Vec<String> {
+        if start >= end || !matches!(self.kind(start), TokenKind::Punct(p) if p == ":") {
+            return Vec::new();
+        }
+        self.split_top_level_plus(start + 1, end)
+    }
+
+    fn split_top_level_plus(&self, start: usize, end: usize) -> Vec<String> {
+        let mut bounds = Vec::new();
+        let mut i = start;
+        let mut depth = 0i32;
+        let mut seg_start = start;
+        while i < end {
+            match self.kind(i) {
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Punct(p) if p == "+" && depth == 0 => {
+                    let b = self.text_between(seg_start, i);
+                    if !b.is_empty() {
+                        bounds.push(b);
+                    }
+                    seg_start = i + 1;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        let b = self.text_between(seg_start, end);
+        if !b.is_empty() {
+            bounds.push(b);
+        }
+        bounds
+    }
+
+    /// Parses an optional trailing `where ...` clause, stopping before the
+    /// item body opener (`{`, `;`, or `=`). Returns the raw `Type: bounds`
+    /// clauses and folds bounds into `generics` by matching names.
+    fn parse_where_clause(&mut self, generics: &mut [GenericParam]) -> Vec<String> {
+        if !self.is_ident("where") {
+            return Vec::new();
+        }
+        self.bump();
+        let mut clauses = Vec::new();
+        loop {
+            if self.is_eof() || self.is_punct("{") || self.is_punct(";") || self.is_punct("=") {
+                break;
+            }
+            let clause_start = self.pos;
+            let mut depth = 0i32;
+            loop {
+                match self.cur() {
+                    TokenKind::Eof => break,
+                    TokenKind::Punct(p) if p == "{" && depth == 0 => break,
+                    TokenKind::Punct(p) if p == ";" && depth == 0 => break,
+                    TokenKind::Punct(p) if p == "=" && depth == 0 => break,
+                    TokenKind::Punct(p) if p == "," && depth == 0 => break,
+                    TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => {
+                        depth += 1;
+                        self.bump();
+                    }
+                    TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => {
+                        depth -= 1;
+                        self.bump();
+                    }
+                    _ => {
+                        self.bump();
+                    }
+                }
+            }
+            let clause_end = self.pos;
+            let clause_text = self.text_between(clause_start, clause_end);
+            if !clause_text.is_empty() {
+                if let Some((lhs, rhs)) = clause_text.split_once(':') {
+                    let lhs = lhs.trim();
+                    for b in rhs.split('+').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                        if let Some(g) = generics.iter_mut().find(|g| g.name == lhs) {
+                            if !g.bounds.iter().any(|x| x == b) {
+                                g.bounds.push(b.to_string());
+                            }
+                        }
+                    }
+                }
+                clauses.push(clause_text);
+            }
+            if self.is_punct(",") {
+                self.bump();
+            }
+        }
+        clauses
+    }
+
+    // -- item dispatch -------------------------------------------------------------
+
+    /// Parses a sequence of items. `enclosed` says whether a `}` here is
+    /// the legitimate close of a real block the caller already opened
+    /// (module body) — if so it ends the loop for the caller to consume.
+    /// At the top level of a file there is no such block, so a `}` there
+    /// is itself unexpected and must be recovered from rather than
+    /// silently ending the parse.
+    fn parse_items(&mut self, parent_path: &str, enclosed: bool) -> Vec<Item> {
+        let mut items = Vec::new();
+        loop {
+            let doc = self.collect_doc_and_skip_trivia();
+            self.skip_attributes();
+            if self.is_eof() || (enclosed && self.is_punct("}")) {
+                break;
+            }
+            if let Some(item) = self.parse_item(parent_path, doc) {
+                items.push(item);
+            } else {
+                items.push(self.recover_unexpected(parent_path, enclosed));
+            }
+        }
+        items
+    }
+
+    fn parse_item(&mut self, parent_path: &str, doc: Option<String>) -> Option<Item> {
+        let start = self.pos;
+        let visibility = self.parse_visibility();
+
+        if self.is_ident("struct") {
+            return Some(self.parse_struct(parent_path, visibility, doc, start));
+        }
+        if self.is_ident("enum") {
+            return Some(self.parse_enum(parent_path, visibility, doc, start));
+        }
+        if self.is_ident("trait") {
+            return Some(self.parse_trait(parent_path, visibility, doc, start));
+        }
+        if self.is_ident("impl") {
+            return Some(self.parse_impl(parent_path, doc, start));
+        }
+        if self.is_ident("mod") {
+            return Some(self.parse_mod(parent_path, visibility, doc, start));
+        }
+        if self.is_ident("const") && !matches!(self.kind(self.pos + 1), TokenKind::Ident(x) if x == "fn")
+        {
+            return Some(self.parse_const_or_static(parent_path, visibility, doc, start, ItemKind::Const));
+        }
+        if self.is_ident("static") {
+            return Some(self.parse_const_or_static(parent_path, visibility, doc, start, ItemKind::Static));
+        }
+        if self.is_ident("type") {
+            return Some(self.parse_type_alias(parent_path, visibility, doc, start));
+        }
+        if self.is_ident("use") {
+            return Some(self.parse_use(parent_path, doc, start));
+        }
+        if self.is_ident("macro_rules") && matches!(self.kind(self.pos + 1), TokenKind::Punct(p) if p == "!")
+        {
+            return Some(self.parse_macro_rules(parent_path, doc, start));
+        }
+        if self.at_fn_start() {
+            return Some(self.parse_fn(parent_path, visibility, doc, start, ItemKind::Fn));
+        }
+        self.pos = start;
+        None
+    }
+
+    /// True when the current token could plausibly begin a new item, used
+    /// by [`Self::recover_unexpected`] as a resynchronization point.
+    fn at_item_boundary(&self) -> bool {
+        if matches!(self.cur(), TokenKind::DocComment { .. }) || self.is_punct("#") {
+            return true;
+        }
+        if self.is_ident("pub") || self.at_fn_start() {
+            return true;
+        }
+        matches!(self.cur(), TokenKind::Ident(x) if matches!(
+            x.as_str(),
+            "struct" | "enum" | "trait" | "impl" | "mod" | "const" | "static" | "type" | "use" | "macro_rules"
+        ))
+    }
+
+    /// Called when [`Self::parse_item`] fails to recognize the token at the
+    /// current position. Skips forward until a reliable recovery point — a
+    /// top-level `;`, the enclosing block's closing `}`, or the start of
+    /// the next item — tracking bracket depth so recovery never stops
+    /// inside a nested block. Records the skipped span as an `Error` item
+    /// plus a matching diagnostic so callers still see well-formed items
+    /// on either side of the damage.
+    ///
+    /// `enclosed` must match the caller's own `enclosed`/block context: if
+    /// there's a real block the caller opened, a depth-0 `}` here is its
+    /// legitimate close and is left for the caller to consume. If not
+    /// (e.g. recovering at the top level of a file), that `}` is itself
+    /// the unexpected token — it gets consumed and diagnosed like any
+    /// other piece of damage, instead of silently ending the parse.
+    fn recover_unexpected(&mut self, parent_path: &str, enclosed: bool) -> Item {
+        let start = self.pos;
+        let mut depth = 0i32;
+        loop {
+            if self.is_eof() {
+                break;
+            }
+            if depth == 0 && self.is_punct("}") {
+                if enclosed {
+                    break;
+                }
+                self.bump();
+                continue;
+            }
+            if depth == 0 && self.pos > start && self.at_item_boundary() {
+                break;
+            }
+            match self.cur() {
+                TokenKind::Punct(p) if p == "(" || p == "[" || p == "{" => {
+                    depth += 1;
+                    self.bump();
+                }
+                TokenKind::Punct(p) if (p == ")" || p == "]" || p == "}") && depth > 0 => {
+                    depth -= 1;
+                    self.bump();
+                }
+                TokenKind::Punct(p) if p == ";" && depth == 0 => {
+                    self.bump();
+                    break;
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+        let end = self.pos;
+        let span = Span::new(self.tokens[start].span.start, self.tokens[end.saturating_sub(1).max(start)].span.end);
+        self.diagnostics.push(Diagnostic {
+            message: format!("unexpected token while looking for an item at byte {}", span.start),
+            span,
+        });
+        Item {
+            kind: ItemKind::Error,
+            name: "<error>".to_string(),
+            path: Self::join_path(parent_path, "<error>"),
+            visibility: Visibility::Private,
+            generics: Vec::new(),
+            where_bounds: Vec::new(),
+            doc: None,
+            span,
+            children: Vec::new(),
+        }
+    }
+
+    fn at_fn_start(&self) -> bool {
+        let mut i = self.pos;
+        loop {
+            match self.kind(i) {
+                TokenKind::Ident(x) if x == "async" || x == "unsafe" || x == "const" => i += 1,
+                TokenKind::Ident(x) if x == "extern" => {
+                    i += 1;
+                    if matches!(self.kind(i), TokenKind::StringLit(_)) {
+                        i += 1;
+                    }
+                }
+                TokenKind::Ident(x) if x == "fn" => return true,
+                _ => return false,
+            }
+        }
+    }
+
+    fn expect_ident_name(&mut self) -> String {
+        if let TokenKind::Ident(n) = self.cur() {
+            let n = n.clone();
+            self.bump();
+            n
+        } else {
+            String::new()
+        }
+    }
+
+    // -- struct / enum ---------------------------------------------------------
+
+    fn parse_struct(&mut self, parent_path: &str, visibility: Visibility, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // struct
+        let name = self.expect_ident_name();
+        let mut generics = self.parse_generics();
+        let where_bounds = self.parse_where_clause(&mut generics);
+        let path = Self::join_path(parent_path, &name);
+        let mut children = Vec::new();
+        if self.is_punct("{") {
+            children = self.parse_fields(&path);
+            // parse_fields leaves pos just past the closing '}'.
+        } else if self.is_punct("(") {
+            let end = self.skip_balanced(self.pos);
+            self.pos = end;
+            if self.is_punct(";") {
+                self.bump();
+            }
+        } else if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind: ItemKind::Struct,
+            name,
+            path,
+            visibility,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children,
+        }
+    }
+
+    fn parse_fields(&mut self, parent_path: &str) -> Vec<Item> {
+        self.bump(); // {
+        let mut fields = Vec::new();
+        loop {
+            let doc = self.collect_doc_and_skip_trivia();
+            self.skip_attributes();
+            if self.is_eof() || self.is_punct("}") {
+                break;
+            }
+            let field_start = self.pos;
+            let visibility = self.parse_visibility();
+            let name = self.expect_ident_name();
+            if self.is_punct(":") {
+                self.bump();
+            }
+            self.advance_until_top_level(&[",", "}"]);
+            let field_end = self.pos;
+            fields.push(Item {
+                kind: ItemKind::Field,
+                name: name.clone(),
+                path: Self::join_path(parent_path, &name),
+                visibility,
+                generics: Vec::new(),
+                where_bounds: Vec::new(),
+                doc,
+                span: Span::new(
+                    self.tokens[field_start].span.start,
+                    self.tokens[field_end.saturating_sub(1).max(field_start)].span.end,
+                ),
+                children: Vec::new(),
+            });
+            if self.is_punct(",") {
+                self.bump();
+            }
+        }
+        if self.is_punct("}") {
+            self.bump();
+        }
+        fields
+    }
+
+    fn parse_enum(&mut self, parent_path: &str, visibility: Visibility, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // enum
+        let name = self.expect_ident_name();
+        let mut generics = self.parse_generics();
+        let where_bounds = self.parse_where_clause(&mut generics);
+        let path = Self::join_path(parent_path, &name);
+        let mut variants = Vec::new();
+        if self.is_punct("{") {
+            self.bump();
+            loop {
+                let vdoc = self.collect_doc_and_skip_trivia();
+                self.skip_attributes();
+                if self.is_eof() || self.is_punct("}") {
+                    break;
+                }
+                let v_start = self.pos;
+                let vname = self.expect_ident_name();
+                let vpath = Self::join_path(&path, &vname);
+                let mut vchildren = Vec::new();
+                if self.is_punct("{") {
+                    vchildren = self.parse_fields(&vpath);
+                } else if self.is_punct("(") {
+                    let end = self.skip_balanced(self.pos);
+                    self.pos = end;
+                }
+                let v_end = self.pos;
+                variants.push(Item {
+                    kind: ItemKind::EnumVariant,
+                    name: vname,
+                    path: vpath,
+                    visibility: Visibility::Public,
+                    generics: Vec::new(),
+                    where_bounds: Vec::new(),
+                    doc: vdoc,
+                    span: Span::new(self.tokens[v_start].span.start, self.tokens[v_end.saturating_sub(1)].span.end),
+                    children: vchildren,
+                });
+                if self.is_punct(",") {
+                    self.bump();
+                }
+            }
+            if self.is_punct("}") {
+                self.bump();
+            }
+        }
+        Item {
+            kind: ItemKind::Enum,
+            name,
+            path,
+            visibility,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: variants,
+        }
+    }
+
+    // -- trait / impl (method containers) --------------------------------------
+
+    fn parse_trait(&mut self, parent_path: &str, visibility: Visibility, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // trait
+        let name = self.expect_ident_name();
+        let mut generics = self.parse_generics();
+        if self.is_punct(":") {
+            // Supertrait bounds; skip to `where` or the body.
+            self.bump();
+            self.advance_until_top_level(&["{"]);
+        }
+        let where_bounds = self.parse_where_clause(&mut generics);
+        let path = Self::join_path(parent_path, &name);
+        let children = if self.is_punct("{") {
+            self.parse_member_block(&path)
+        } else {
+            if self.is_punct(";") {
+                self.bump();
+            }
+            Vec::new()
+        };
+        Item {
+            kind: ItemKind::Trait,
+            name,
+            path,
+            visibility,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children,
+        }
+    }
+
+    /// Extracts just the self-type name from an `impl` header's token
+    /// range (`[head_start, head_end)`, i.e. everything between `impl<..>`
+    /// and `where`/`{`), dropping a leading `Trait for ` and any trailing
+    /// `<...>` generic arguments: `MyTrait for MyStruct` -> `MyStruct`,
+    /// `TypedMap<K, V>` -> `TypedMap`.
+    fn impl_self_type(&self, head_start: usize, head_end: usize) -> String {
+        let mut self_start = head_start;
+        let mut depth = 0i32;
+        for i in head_start..head_end {
+            match self.kind(i) {
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Ident(x) if x == "for" && depth == 0 => self_start = i + 1,
+                _ => {}
+            }
+        }
+        let mut self_end = head_end;
+        let mut depth = 0i32;
+        for i in self_start..head_end {
+            match self.kind(i) {
+                TokenKind::Punct(p) if p == "<" => {
+                    if depth == 0 {
+                        self_end = i;
+                        break;
+                    }
+                    depth += 1;
+                }
+                TokenKind::Punct(p) if p == ">" => depth -= 1,
+                _ => {}
+            }
+        }
+        self.text_between(self_start, self_end)
+    }
+
+    fn parse_impl(&mut self, parent_path: &str, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // impl
+        let mut generics = self.parse_generics();
+        let head_start = self.pos;
+        // Advance to `where` or the opening `{`, tracking `<...>` depth so
+        // the `for` separator in `impl<T> Trait<T> for Foo<T>` isn't lost.
+        let mut depth = 0i32;
+        loop {
+            match self.cur() {
+                TokenKind::Eof => break,
+                TokenKind::Punct(p) if p == "{" && depth == 0 => break,
+                TokenKind::Ident(x) if x == "where" && depth == 0 => break,
+                TokenKind::Punct(p) if p == "<" => {
+                    depth += 1;
+                    self.bump();
+                }
+                TokenKind::Punct(p) if p == ">" => {
+                    depth -= 1;
+                    self.bump();
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+        let head_end = self.pos;
+        let name = self.text_between(head_start, head_end);
+        let self_type = self.impl_self_type(head_start, head_end);
+        let where_bounds = self.parse_where_clause(&mut generics);
+        // `path` is built from just the self-type (e.g. `MyStruct`, not
+        // the full `MyTrait for MyStruct` header) so a method inside gets
+        // a clean, `::`-splittable path like `MyStruct::do_work` rather
+        // than one with an embedded `for` keyword and whitespace.
+        let path = Self::join_path(parent_path, &self_type);
+        let children = if self.is_punct("{") {
+            self.parse_member_block(&path)
+        } else {
+            if self.is_punct(";") {
+                self.bump();
+            }
+            Vec::new()
+        };
+        Item {
+            kind: ItemKind::Impl,
+            name,
+            path,
+            visibility: Visibility::Private,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children,
+        }
+    }
+
+    /// Parses the `{ ... }` body of a trait or impl: nested `fn`s become
+    /// `Method` items and associated `type`s become `AssocType` items.
+    fn parse_member_block(&mut self, parent_path: &str) -> Vec<Item> {
+        self.bump(); // {
+        let mut members = Vec::new();
+        loop {
+            let doc = self.collect_doc_and_skip_trivia();
+            self.skip_attributes();
+            if self.is_eof() || self.is_punct("}") {
+                break;
+            }
+            let m_start = self.pos;
+            let visibility = self.parse_visibility();
+            if self.at_fn_start() {
+                members.push(self.parse_fn(parent_path, visibility, doc, m_start, ItemKind::Method));
+            } else if self.is_ident("type") {
+                members.push(self.parse_type_alias_kind(parent_path, visibility, doc, m_start, ItemKind::AssocType));
+            } else if self.is_ident("const") {
+                members.push(self.parse_const_or_static(parent_path, visibility, doc, m_start, ItemKind::Const));
+            } else {
+                self.pos = m_start;
+                members.push(self.recover_unexpected(parent_path, true));
+            }
+        }
+        if self.is_punct("}") {
+            self.bump();
+        }
+        members
+    }
+
+    fn parse_fn(
+        &mut self,
+        parent_path: &str,
+        visibility: Visibility,
+        doc: Option<String>,
+        start: usize,
+        kind: ItemKind,
+    ) -> Item {
+        while matches!(self.cur(), TokenKind::Ident(x) if x == "async" || x == "unsafe" || x == "const") {
+            self.bump();
+        }
+        if self.is_ident("extern") {
+            self.bump();
+            if matches!(self.cur(), TokenKind::StringLit(_)) {
+                self.bump();
+            }
+        }
+        self.bump(); // fn
+        let name = self.expect_ident_name();
+        let mut generics = self.parse_generics();
+        if self.is_punct("(") {
+            let end = self.skip_balanced(self.pos);
+            self.pos = end;
+        }
+        if self.is_punct("->") {
+            self.bump();
+            self.advance_until_top_level(&["{", ";", "where"]);
+        }
+        let where_bounds = self.parse_where_clause(&mut generics);
+        if self.is_punct("{") {
+            let end = self.skip_balanced(self.pos);
+            self.pos = end;
+        } else if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind,
+            name: name.clone(),
+            path: Self::join_path(parent_path, &name),
+            visibility,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: Vec::new(),
+        }
+    }
+
+    // -- mod ---------------------------------------------------------------------
+
+    fn parse_mod(&mut self, parent_path: &str, visibility: Visibility, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // mod
+        let name = self.expect_ident_name();
+        let path = Self::join_path(parent_path, &name);
+        let children = if self.is_punct("{") {
+            self.bump();
+            let items = self.parse_items(&path, true);
+            if self.is_punct("}") {
+                self.bump();
+            }
+            items
+        } else {
+            if self.is_punct(";") {
+                self.bump();
+            }
+            Vec::new()
+        };
+        Item {
+            kind: ItemKind::Mod,
+            name,
+            path,
+            visibility,
+            generics: Vec::new(),
+            where_bounds: Vec::new(),
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children,
+        }
+    }
+
+    // -- const / static / type alias / use / macro_rules --------------------------
+
+    fn parse_const_or_static(
+        &mut self,
+        parent_path: &str,
+        visibility: Visibility,
+        doc: Option<String>,
+        start: usize,
+        kind: ItemKind,
+    ) -> Item {
+        self.bump(); // const | static
+        if self.is_ident("mut") {
+            self.bump();
+        }
+        let name = self.expect_ident_name();
+        self.advance_until_top_level(&[";"]);
+        if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind,
+            name: name.clone(),
+            path: Self::join_path(parent_path, &name),
+            visibility,
+            generics: Vec::new(),
+            where_bounds: Vec::new(),
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: Vec::new(),
+        }
+    }
+
+    fn parse_type_alias(&mut self, parent_path: &str, visibility: Visibility, doc: Option<String>, start: usize) -> Item {
+        self.parse_type_alias_kind(parent_path, visibility, doc, start, ItemKind::TypeAlias)
+    }
+
+    fn parse_type_alias_kind(
+        &mut self,
+        parent_path: &str,
+        visibility: Visibility,
+        doc: Option<String>,
+        start: usize,
+        kind: ItemKind,
+    ) -> Item {
+        self.bump(); // type
+        let name = self.expect_ident_name();
+        let mut generics = self.parse_generics();
+        let where_bounds = self.parse_where_clause(&mut generics);
+        self.advance_until_top_level(&[";"]);
+        if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind,
+            name: name.clone(),
+            path: Self::join_path(parent_path, &name),
+            visibility,
+            generics,
+            where_bounds,
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: Vec::new(),
+        }
+    }
+
+    fn parse_use(&mut self, parent_path: &str, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // use
+        let name_start = self.pos;
+        self.advance_until_top_level(&[";"]);
+        let name = self.text_between(name_start, self.pos);
+        if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind: ItemKind::Use,
+            name: name.clone(),
+            path: Self::join_path(parent_path, &name),
+            visibility: Visibility::Private,
+            generics: Vec::new(),
+            where_bounds: Vec::new(),
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: Vec::new(),
+        }
+    }
+
+    fn parse_macro_rules(&mut self, parent_path: &str, doc: Option<String>, start: usize) -> Item {
+        self.bump(); // macro_rules
+        self.bump(); // !
+        let name = self.expect_ident_name();
+        if matches!(self.cur(), TokenKind::Punct(p) if p == "{" || p == "(" || p == "[") {
+            let end = self.skip_balanced(self.pos);
+            self.pos = end;
+        }
+        if self.is_punct(";") {
+            self.bump();
+        }
+        Item {
+            kind: ItemKind::MacroRules,
+            name: name.clone(),
+            path: Self::join_path(parent_path, &name),
+            visibility: Visibility::Private,
+            generics: Vec::new(),
+            where_bounds: Vec::new(),
+            doc,
+            span: Span::new(self.tokens[start].span.start, self.tokens[self.pos.saturating_sub(1)].span.end),
+            children: Vec::new(),
+        }
+    }
+}
+
+pub fn parse(src: impl Into<String>) -> ParsedFile {
+    Parser::new(src).parse_file()
+}
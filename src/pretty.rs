@@ -0,0 +1,314 @@
+//! Deterministic textual dump of a parsed tree, insensitive to the
+//! original source's whitespace and comments. Two differently-formatted
+//! but semantically-equivalent sources produce identical output, which is
+//! what makes it usable as a snapshot-test baseline: a real regression (a
+//! dropped `where` clause, a lost `async` marker, a mishandled
+//! `extern "C"`) shows up as a diff against a committed golden file
+//! instead of hiding behind reformatted input.
+
+use crate::ast::{GenericParamKind, Item, ItemKind, ParsedFile, Visibility};
+use crate::lexer::{lex, Token, TokenKind};
+use crate::token_util::{is_ident, is_punct, skip_angle_balanced, skip_balanced};
+
+impl ParsedFile {
+    /// Renders every item as one indented line per declaration, recursing
+    /// into children. `src` is needed to recover a function's parameter
+    /// list, return type, and `async`/`unsafe`/`extern` modifiers, which
+    /// the item tree only tracks as a byte span.
+    pub fn pretty_print(&self, src: &str) -> String {
+        let mut out = String::new();
+        for item in &self.items {
+            render_item(item, src, 0, &mut out);
+        }
+        out
+    }
+}
+
+fn indent(depth: usize, out: &mut String) {
+    for _ in 0..depth {
+        out.push_str("    ");
+    }
+}
+
+fn visibility_str(v: Visibility) -> &'static str {
+    match v {
+        Visibility::Public => "pub ",
+        Visibility::PubCrate => "pub(crate) ",
+        Visibility::Private => "",
+    }
+}
+
+fn kind_str(k: ItemKind) -> &'static str {
+    match k {
+        ItemKind::Struct => "struct",
+        ItemKind::Enum => "enum",
+        ItemKind::EnumVariant => "variant",
+        ItemKind::Field => "field",
+        ItemKind::Trait => "trait",
+        ItemKind::Impl => "impl",
+        ItemKind::Fn => "fn",
+        ItemKind::Method => "method",
+        ItemKind::AssocType => "assoc_type",
+        ItemKind::Mod => "mod",
+        ItemKind::Const => "const",
+        ItemKind::Static => "static",
+        ItemKind::TypeAlias => "type",
+        ItemKind::MacroRules => "macro_rules",
+        ItemKind::Use => "use",
+        ItemKind::Error => "error",
+    }
+}
+
+fn generics_suffix(item: &Item) -> String {
+    if item.generics.is_empty() {
+        return String::new();
+    }
+    let parts: Vec<String> = item
+        .generics
+        .iter()
+        .map(|g| {
+            let name = match g.kind {
+                GenericParamKind::Lifetime => format!("'{}", g.name),
+                GenericParamKind::Const => format!("const {}", g.name),
+                GenericParamKind::Type => g.name.clone(),
+            };
+            if g.bounds.is_empty() {
+                name
+            } else {
+                format!("{name}: {}", g.bounds.join(" + "))
+            }
+        })
+        .collect();
+    format!("<{}>", parts.join(", "))
+}
+
+fn where_suffix(item: &Item) -> String {
+    if item.where_bounds.is_empty() {
+        String::new()
+    } else {
+        format!(" where {}", item.where_bounds.join(", "))
+    }
+}
+
+fn render_item(item: &Item, src: &str, depth: usize, out: &mut String) {
+    indent(depth, out);
+    if matches!(item.kind, ItemKind::Fn | ItemKind::Method) {
+        render_fn(item, src, out);
+    } else if item.kind == ItemKind::Impl {
+        // `item.name` is the header text after `impl<..>` (e.g.
+        // `TypedMap<K, V>` or `MyTrait for MyStruct`); the
+        // impl's own generic parameters belong before it, not after, as in
+        // `impl<K, V> TypedMap<K, V>`.
+        out.push_str("impl");
+        out.push_str(&generics_suffix(item));
+        out.push(' ');
+        out.push_str(&item.name);
+        out.push_str(&where_suffix(item));
+    } else {
+        // Enum variants always carry `Visibility::Public` in the item
+        // tree (they're as visible as their enum, with no `pub` keyword
+        // of their own in source), so a visibility prefix here would be
+        // noise that never appeared in the input.
+        if item.kind != ItemKind::EnumVariant {
+            out.push_str(visibility_str(item.visibility));
+        }
+        out.push_str(kind_str(item.kind));
+        out.push(' ');
+        out.push_str(&item.name);
+        out.push_str(&generics_suffix(item));
+        out.push_str(&where_suffix(item));
+    }
+    out.push('\n');
+    for child in &item.children {
+        render_item(child, src, depth + 1, out);
+    }
+}
+
+/// A function/method signature re-lexed from its own span text, since the
+/// [`Item`] tree only tracks a function's modifiers, parameters and
+/// return type as an opaque byte range.
+struct FnSignature {
+    is_const: bool,
+    is_async: bool,
+    is_unsafe: bool,
+    extern_abi: Option<String>,
+    params: String,
+    return_type: Option<String>,
+    has_body: bool,
+}
+
+fn render_fn(item: &Item, src: &str, out: &mut String) {
+    let sig = fn_signature(item, src);
+    out.push_str(visibility_str(item.visibility));
+    if sig.is_const {
+        out.push_str("const ");
+    }
+    if sig.is_async {
+        out.push_str("async ");
+    }
+    if sig.is_unsafe {
+        out.push_str("unsafe ");
+    }
+    if let Some(abi) = &sig.extern_abi {
+        out.push_str("extern ");
+        if !abi.is_empty() {
+            out.push_str(abi);
+            out.push(' ');
+        }
+    }
+    out.push_str(kind_str(item.kind));
+    out.push(' ');
+    out.push_str(&item.name);
+    out.push_str(&generics_suffix(item));
+    out.push('(');
+    out.push_str(&sig.params);
+    out.push(')');
+    if let Some(ret) = &sig.return_type {
+        out.push_str(" -> ");
+        out.push_str(ret);
+    }
+    out.push_str(&where_suffix(item));
+    out.push_str(if sig.has_body { " { .. }" } else { ";" });
+}
+
+fn fn_signature(item: &Item, src: &str) -> FnSignature {
+    let text = &src[item.span.start..item.span.end];
+    let tokens = lex(text);
+    let mut i = 0;
+
+    if is_ident(&tokens[i].kind, "pub") {
+        i += 1;
+        if is_punct(&tokens[i].kind, "(") {
+            i = skip_balanced(&tokens, i);
+        }
+    }
+
+    let mut is_const = false;
+    let mut is_async = false;
+    let mut is_unsafe = false;
+    let mut extern_abi = None;
+    loop {
+        if is_ident(&tokens[i].kind, "unsafe") {
+            is_unsafe = true;
+            i += 1;
+        } else if is_ident(&tokens[i].kind, "async") {
+            is_async = true;
+            i += 1;
+        } else if is_ident(&tokens[i].kind, "const") {
+            is_const = true;
+            i += 1;
+        } else if is_ident(&tokens[i].kind, "extern") {
+            i += 1;
+            extern_abi = Some(match &tokens[i].kind {
+                TokenKind::StringLit(abi) => {
+                    let abi = abi.clone();
+                    i += 1;
+                    abi
+                }
+                _ => String::new(),
+            });
+        } else {
+            break;
+        }
+    }
+    // tokens[i] is "fn"; tokens[i + 1] is the function name.
+    i += 2;
+    if is_punct(&tokens[i].kind, "<") {
+        i = skip_angle_balanced(&tokens, i);
+    }
+    let mut params = String::new();
+    if is_punct(&tokens[i].kind, "(") {
+        let close = skip_balanced(&tokens, i);
+        params = render_params(&tokens[i + 1..close - 1], text);
+        i = close;
+    }
+    let mut return_type = None;
+    if is_punct(&tokens[i].kind, "->") {
+        i += 1;
+        let ret_start = i;
+        let mut depth = 0i32;
+        while i < tokens.len() {
+            match &tokens[i].kind {
+                TokenKind::Punct(p) if p == "{" && depth == 0 => break,
+                TokenKind::Punct(p) if p == ";" && depth == 0 => break,
+                TokenKind::Ident(x) if x == "where" && depth == 0 => break,
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Eof => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        let ret_text = join_tokens(&tokens[ret_start..i], text);
+        if !ret_text.is_empty() && ret_text != "()" {
+            return_type = Some(ret_text);
+        }
+    }
+    // Skip over an optional `where` clause to find whether a body follows.
+    while !matches!(&tokens[i].kind, TokenKind::Punct(p) if p == "{" || p == ";")
+        && !matches!(tokens[i].kind, TokenKind::Eof)
+    {
+        i += 1;
+    }
+    let has_body = matches!(&tokens[i].kind, TokenKind::Punct(p) if p == "{");
+
+    FnSignature {
+        is_const,
+        is_async,
+        is_unsafe,
+        extern_abi,
+        params,
+        return_type,
+        has_body,
+    }
+}
+
+/// Renders top-level comma-separated parameter segments as normalized
+/// text (`self`, `&self`, `name: Type`, ...), joined with `", "`.
+fn render_params(tokens: &[Token], text: &str) -> String {
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let seg_start = i;
+        let mut depth = 0i32;
+        while i < tokens.len() {
+            match &tokens[i].kind {
+                TokenKind::Punct(p) if p == "<" || p == "(" || p == "[" => depth += 1,
+                TokenKind::Punct(p) if p == ">" || p == ")" || p == "]" => depth -= 1,
+                TokenKind::Punct(p) if p == "," && depth == 0 => break,
+                _ => {}
+            }
+            i += 1;
+        }
+        if i > seg_start {
+            segments.push(join_tokens(&tokens[seg_start..i], text));
+        }
+        if i < tokens.len() {
+            i += 1; // skip comma
+        }
+    }
+    segments.join(", ")
+}
+
+/// Joins a token slice back into normalized source text: each token's own
+/// span text, with a single space inserted between tokens except where
+/// that would separate punctuation (`::`, `.`, `<T>`, `(x)`, `&x`, `*x`,
+/// `x,`, `x;`) that reads better tight. Used to recover a readable,
+/// whitespace-independent rendering of a type or return-type region that
+/// the [`Item`] tree doesn't store structurally.
+fn join_tokens(tokens: &[Token], text: &str) -> String {
+    let mut out = String::new();
+    for (idx, token) in tokens.iter().enumerate() {
+        if idx > 0 && needs_space(&tokens[idx - 1].kind, &token.kind) {
+            out.push(' ');
+        }
+        out.push_str(&text[token.span.start..token.span.end]);
+    }
+    out
+}
+
+fn needs_space(prev: &TokenKind, cur: &TokenKind) -> bool {
+    let tight_before = matches!(cur, TokenKind::Punct(p) if matches!(p.as_str(), "," | ";" | ")" | "]" | ">" | "::" | "." | "<" | ":"));
+    let tight_after = matches!(prev, TokenKind::Punct(p) if matches!(p.as_str(), "(" | "[" | "<" | "::" | "." | "&" | "*"));
+    !tight_before && !tight_after
+}
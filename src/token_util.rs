@@ -0,0 +1,59 @@
+//! Small token-slice helpers shared by the parser and the tools that
+//! re-lex an item's own span (doc-lint, pretty-print) to recover details
+//! the [`Item`](crate::ast::Item) tree only tracks as a byte range.
+
+use crate::lexer::{Token, TokenKind};
+
+pub(crate) fn is_ident(token: &TokenKind, name: &str) -> bool {
+    matches!(token, TokenKind::Ident(x) if x == name)
+}
+
+pub(crate) fn is_punct(token: &TokenKind, p: &str) -> bool {
+    matches!(token, TokenKind::Punct(x) if x == p)
+}
+
+/// Skips a `(`/`[`/`{`-delimited region starting at the opening token at
+/// `open`, returning the index just past its matching close (or, if the
+/// input runs out first, the index of the `Eof` token, or the token count
+/// for a slice with no `Eof`).
+pub(crate) fn skip_balanced(tokens: &[Token], open: usize) -> usize {
+    let mut depth = 0i32;
+    let mut i = open;
+    while i < tokens.len() {
+        match &tokens[i].kind {
+            TokenKind::Punct(p) if p == "(" || p == "[" || p == "{" => depth += 1,
+            TokenKind::Punct(p) if p == ")" || p == "]" || p == "}" => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            TokenKind::Eof => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    i
+}
+
+/// Like [`skip_balanced`] but for a `<...>` generic list, valid only in
+/// item-header position where `<`/`>` cannot be comparison/shift operators.
+pub(crate) fn skip_angle_balanced(tokens: &[Token], open: usize) -> usize {
+    let mut depth = 0i32;
+    let mut i = open;
+    while i < tokens.len() {
+        match &tokens[i].kind {
+            TokenKind::Punct(p) if p == "<" => depth += 1,
+            TokenKind::Punct(p) if p == ">" => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            TokenKind::Eof => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    i
+}
@@ -0,0 +1,138 @@
+//! Combines every file matched by a glob pattern into one index, and
+//! resolves the `use` statements seen across it against the modules the
+//! parse discovered — answering "which files import symbol X" and "what
+//! does `path::like::this` resolve to" over the whole set at once.
+
+use std::path::{Path, PathBuf};
+
+use crate::ast::{Item, ItemKind, ParsedFile};
+use crate::glob::Glob;
+use crate::parser::parse;
+
+/// Whether a `use` path refers to something declared in this workspace or
+/// to an external crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UseOrigin {
+    /// Rooted at `crate::`/`self::`/`super::`, or whose first segment
+    /// names a module declared at the top level of a workspace file.
+    IntraCrate,
+    /// Everything else (e.g. `std::...`, `serde::...`).
+    External,
+}
+
+/// One parsed file plus the path it was read from.
+#[derive(Debug, Clone)]
+pub struct WorkspaceFile {
+    pub path: PathBuf,
+    pub parsed: ParsedFile,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    pub files: Vec<WorkspaceFile>,
+}
+
+impl Workspace {
+    /// Parses every file under `root` matching `pattern` (e.g.
+    /// `src/**/*.rs`) into one combined workspace.
+    pub fn from_glob(root: &Path, pattern: &str) -> Self {
+        let glob = Glob::compile(pattern);
+        let mut files: Vec<WorkspaceFile> = glob
+            .walk(root)
+            .into_iter()
+            .filter_map(|path| {
+                let src = std::fs::read_to_string(&path).ok()?;
+                Some(WorkspaceFile {
+                    path,
+                    parsed: parse(src),
+                })
+            })
+            .collect();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Self { files }
+    }
+
+    /// All top-level items across every file, as one combined crate-root
+    /// namespace.
+    fn root_items(&self) -> impl Iterator<Item = &Item> {
+        self.files.iter().flat_map(|f| f.parsed.items.iter())
+    }
+
+    /// Names of every `mod` item declared at the crate root, used to tell
+    /// an intra-crate `use` apart from an external-crate one.
+    fn declared_module_names(&self) -> Vec<&str> {
+        self.root_items()
+            .filter(|i| i.kind == ItemKind::Mod)
+            .map(|i| i.name.as_str())
+            .collect()
+    }
+
+    /// Classifies a `use` import path as intra-crate or external.
+    pub fn classify_use(&self, path: &str) -> UseOrigin {
+        if path.starts_with("crate::") || path.starts_with("self::") || path.starts_with("super::") {
+            return UseOrigin::IntraCrate;
+        }
+        let first_segment = path.split("::").next().unwrap_or(path);
+        if self.declared_module_names().contains(&first_segment) {
+            UseOrigin::IntraCrate
+        } else {
+            UseOrigin::External
+        }
+    }
+
+    /// Paths of files with a top-level `use` mentioning `symbol` — either
+    /// as the import's final segment or as a name inside a `{self, ...}`
+    /// group.
+    pub fn files_importing(&self, symbol: &str) -> Vec<&Path> {
+        self.files
+            .iter()
+            .filter(|f| {
+                f.parsed.items.iter().any(|item| {
+                    item.kind == ItemKind::Use
+                        && (item.name.rsplit("::").next() == Some(symbol)
+                            || item
+                                .name
+                                .rsplit_once("::")
+                                .map(|(_, group)| group.trim_matches(['{', '}']))
+                                .is_some_and(|group| group.split(',').any(|s| s.trim() == symbol)))
+                })
+            })
+            .map(|f| f.path.as_path())
+            .collect()
+    }
+
+    /// Resolves a `::`-separated path (optionally `crate::`/`self::`/
+    /// `super::`-rooted) against the items discovered in this workspace.
+    ///
+    /// Matches root items by `path` rather than `name`: an `impl` item's
+    /// `name` is its full header (`MyTrait for MyStruct`), but its `path`
+    /// is just the self-type (`MyStruct`), which is what a caller's
+    /// `::`-path actually refers to. Since a struct and its impls (or
+    /// several impls of the same type) legitimately share that first
+    /// segment, every matching candidate at each level is tried until one
+    /// yields a full resolution, rather than committing to the first match.
+    pub fn resolve(&self, path: &str) -> Option<&Item> {
+        let mut remainder = path;
+        for prefix in ["crate::", "self::", "super::"] {
+            if let Some(stripped) = remainder.strip_prefix(prefix) {
+                remainder = stripped;
+            }
+        }
+        let segments: Vec<&str> = remainder.split("::").collect();
+        let (first, rest) = segments.split_first()?;
+        self.root_items()
+            .filter(|item| item.path == *first)
+            .find_map(|item| resolve_in(item, rest))
+    }
+}
+
+fn resolve_in<'a>(item: &'a Item, segments: &[&str]) -> Option<&'a Item> {
+    match segments.split_first() {
+        None => Some(item),
+        Some((next, rest)) => item
+            .children
+            .iter()
+            .filter(|child| child.name == *next)
+            .find_map(|child| resolve_in(child, rest)),
+    }
+}
@@ -0,0 +1,66 @@
+use usereq::{doc_lint, parse};
+
+const FIXTURE: &str = include_str!("fixtures/fixture_rust.rs");
+const MISMATCH: &str = include_str!("fixtures/fixture_doc_mismatch.rs");
+
+#[test]
+fn well_documented_fixture_has_no_diagnostics() {
+    let parsed = parse(FIXTURE);
+    let diagnostics = doc_lint::check(&parsed, FIXTURE);
+    assert!(
+        diagnostics.is_empty(),
+        "expected no doc-lint diagnostics, got: {diagnostics:?}"
+    );
+}
+
+fn messages(src: &str) -> Vec<String> {
+    let parsed = parse(src);
+    doc_lint::check(&parsed, src)
+        .into_iter()
+        .map(|d| d.message)
+        .collect()
+}
+
+#[test]
+fn flags_param_tag_with_no_matching_parameter() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("@param wrong_name") && m.contains("bad_param")));
+}
+
+#[test]
+fn flags_parameter_with_no_param_tag() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("parameter `actual`") && m.contains("bad_param")));
+}
+
+#[test]
+fn flags_tparam_tag_with_no_matching_generic() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("@tparam b") && m.contains("bad_tparam")));
+}
+
+#[test]
+fn flags_tparam_tag_on_a_struct_with_no_matching_generic() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("@tparam Z") && m.contains("BadTparamStruct")));
+}
+
+#[test]
+fn flags_return_tag_on_unit_function() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("no_return_value") && m.contains("returns `()`")));
+}
+
+#[test]
+fn flags_missing_return_tag_on_value_returning_function() {
+    let messages = messages(MISMATCH);
+    assert!(messages
+        .iter()
+        .any(|m| m.contains("missing_return_tag") && m.contains("no @return tag")));
+}
+
+#[test]
+fn flags_unsafe_fn_missing_safety_tag() {
+    let messages = messages(MISMATCH);
+    assert!(messages.iter().any(|m| m.contains("unsafe fn `missing_safety`")));
+}
@@ -0,0 +1,57 @@
+use usereq::ast::ItemKind;
+use usereq::parse;
+
+const FIXTURE_ERR: &str = include_str!("fixtures/fixture_rust_err.rs");
+
+#[test]
+fn recovers_well_formed_items_around_top_level_garbage() {
+    let parsed = parse(FIXTURE_ERR);
+    let names: Vec<&str> = parsed.items.iter().map(|i| i.name.as_str()).collect();
+
+    assert!(names.contains(&"Good"));
+    assert!(names.contains(&"after_garbage"));
+    assert!(names.contains(&"tail"));
+    assert!(
+        parsed.items.iter().any(|i| i.kind == ItemKind::Error),
+        "expected an Error item for the garbage region"
+    );
+}
+
+#[test]
+fn recovers_well_formed_methods_around_a_broken_impl_member() {
+    let parsed = parse(FIXTURE_ERR);
+    let impl_block = parsed
+        .items
+        .iter()
+        .find(|i| i.kind == ItemKind::Impl && i.name == "Good")
+        .unwrap();
+    let member_names: Vec<&str> = impl_block.children.iter().map(|i| i.name.as_str()).collect();
+
+    assert!(member_names.contains(&"before"));
+    assert!(member_names.contains(&"after"));
+    assert!(impl_block.children.iter().any(|i| i.kind == ItemKind::Error));
+}
+
+#[test]
+fn recovers_from_a_stray_closing_brace_at_the_top_level() {
+    // A `}` with no enclosing block to legitimately close used to make
+    // the top-level item loop stop dead, silently dropping everything
+    // after it.
+    let src = "pub fn a() {}\n}\npub fn b() {}\n";
+    let parsed = parse(src);
+    let names: Vec<&str> = parsed.items.iter().map(|i| i.name.as_str()).collect();
+
+    assert!(names.contains(&"a"));
+    assert!(names.contains(&"b"), "item after the stray `}}` must survive: {names:?}");
+    assert!(!parsed.diagnostics.is_empty(), "the stray `}}` itself should be diagnosed");
+}
+
+#[test]
+fn diagnostics_report_the_recovered_spans() {
+    let parsed = parse(FIXTURE_ERR);
+    assert!(!parsed.diagnostics.is_empty());
+    for diagnostic in &parsed.diagnostics {
+        assert!(diagnostic.span.end > diagnostic.span.start);
+        assert!(!diagnostic.message.is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+/// @file fixture_doc_mismatch.rs
+/// @brief Deliberately mismatched Doxygen tags for the doc-lint pass.
+
+/// Documents a parameter that doesn't exist and omits the one that does.
+///
+/// @param wrong_name This name isn't a real parameter.
+pub fn bad_param(actual: i32) -> i32 {
+    actual
+}
+
+/// Documents a lifetime that doesn't exist on this generic function.
+///
+/// @tparam 'b Not a real lifetime on this signature.
+pub fn bad_tparam<'a>(value: &'a str) -> &'a str {
+    value
+}
+
+/// Claims a return value tag on a function that returns nothing.
+///
+/// @return This function never returns anything.
+pub fn no_return_value(x: i32) {
+    let _ = x;
+}
+
+/// Returns a value but never says so.
+pub fn missing_return_tag(x: i32) -> i32 {
+    x
+}
+
+/// Dereferences a raw pointer without documenting the safety contract.
+pub unsafe fn missing_safety(ptr: *const i32) -> i32 {
+    *ptr
+}
+
+/// Documents a type parameter that doesn't exist on this struct.
+///
+/// @tparam Z Not a real generic parameter here.
+pub struct BadTparamStruct<T> {
+    value: T,
+}
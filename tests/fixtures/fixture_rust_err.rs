@@ -0,0 +1,40 @@
+/// @file fixture_rust_err.rs
+/// @brief Deliberately broken Rust used to lock in recovery behavior.
+use std::fmt;
+
+/// A struct that parses fine before the damage below.
+pub struct Good {
+    field: i32,
+}
+
+/// Garbage standing where an item should be; the parser must resynchronize
+/// at the next recognizable item (`pub fn after_garbage`) instead of
+/// aborting the rest of the file.
+this is not valid rust at all ???
+
+/// Should still be recovered as a well-formed function.
+pub fn after_garbage() -> i32 {
+    42
+}
+
+impl Good {
+    /// Well-formed method before the broken one.
+    pub fn before(&self) -> i32 {
+        self.field
+    }
+
+    @@@ totally broken method header @@@ {
+        unreachable!()
+    }
+
+    /// Well-formed method after the broken one; the malformed member above
+    /// must not swallow it.
+    pub fn after(&self) -> i32 {
+        self.field
+    }
+}
+
+/// Trailing well-formed item so EOF recovery can be exercised too.
+pub fn tail() -> &'static str {
+    "still parses"
+}
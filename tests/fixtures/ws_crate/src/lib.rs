@@ -0,0 +1,8 @@
+use std::collections::HashMap;
+use crate::utils::helper::Helper;
+
+pub mod utils;
+
+/// Crate-root marker type used only to give the workspace tests something
+/// intra-crate to resolve.
+pub struct Root;
@@ -0,0 +1,6 @@
+use std::fmt;
+
+/// A tiny type that `lib.rs` imports across the module boundary.
+pub struct Helper {
+    pub value: i32,
+}
@@ -0,0 +1,116 @@
+use usereq::ast::{ItemKind, Visibility};
+use usereq::parse;
+
+const FIXTURE: &str = include_str!("fixtures/fixture_rust.rs");
+
+#[test]
+fn parses_top_level_declarations() {
+    let parsed = parse(FIXTURE);
+    let names: Vec<&str> = parsed.items.iter().map(|i| i.name.as_str()).collect();
+
+    for expected in [
+        "MyStruct",
+        "BorrowedData",
+        "TypedMap",
+        "MyEnum",
+        "MyTrait",
+        "Parser",
+        "my_module",
+        "internal",
+        "hashmap",
+        "my_macro",
+        "MY_CONST",
+        "MY_STATIC",
+        "CONFIG",
+        "IoResult",
+        "MyAlias",
+        "my_function",
+        "async_function",
+        "filter_with",
+        "create_worker",
+        "read_raw",
+        "ffi_double",
+        "describe_enum",
+    ] {
+        assert!(names.contains(&expected), "missing top-level item: {expected}");
+    }
+}
+
+#[test]
+fn captures_lifetime_and_generic_bounds() {
+    let parsed = parse(FIXTURE);
+    let borrowed = parsed.items.iter().find(|i| i.name == "BorrowedData").unwrap();
+    assert_eq!(borrowed.generics.len(), 1);
+    assert_eq!(borrowed.generics[0].name, "a");
+
+    let typed_map = parsed.items.iter().find(|i| i.name == "TypedMap").unwrap();
+    let k = typed_map.generics.iter().find(|g| g.name == "K").unwrap();
+    assert!(k.bounds.iter().any(|b| b == "std::hash::Hash"));
+    assert!(k.bounds.iter().any(|b| b == "Eq"));
+}
+
+#[test]
+fn resolves_nested_module_paths() {
+    let parsed = parse(FIXTURE);
+    let internal = parsed.items.iter().find(|i| i.name == "internal").unwrap();
+    assert_eq!(internal.visibility, Visibility::PubCrate);
+    let counter = internal.children.iter().find(|i| i.name == "COUNTER").unwrap();
+    assert_eq!(counter.path, "internal::COUNTER");
+    assert_eq!(counter.kind, ItemKind::Static);
+}
+
+#[test]
+fn resolves_method_paths_inside_impls() {
+    let parsed = parse(FIXTURE);
+    let impl_block = parsed
+        .items
+        .iter()
+        .find(|i| i.kind == ItemKind::Impl && i.name == "MyStruct")
+        .unwrap();
+    let try_update = impl_block.children.iter().find(|i| i.name == "try_update").unwrap();
+    assert_eq!(try_update.path, "MyStruct::try_update");
+    assert_eq!(try_update.kind, ItemKind::Method);
+}
+
+#[test]
+fn captures_where_clause_bounds_on_a_generic_function() {
+    let parsed = parse(FIXTURE);
+    let filter_with = parsed.items.iter().find(|i| i.name == "filter_with").unwrap();
+    assert!(filter_with.where_bounds.iter().any(|b| b == "F: Fn(&i32) -> bool"));
+    let f = filter_with.generics.iter().find(|g| g.name == "F").unwrap();
+    assert!(f.bounds.iter().any(|b| b == "Fn(&i32) -> bool"));
+}
+
+#[test]
+fn trait_impl_methods_get_a_clean_dotted_path() {
+    let parsed = parse(FIXTURE);
+    let impl_block = parsed
+        .items
+        .iter()
+        .find(|i| i.kind == ItemKind::Impl && i.name == "MyTrait for MyStruct")
+        .unwrap();
+    assert_eq!(impl_block.path, "MyStruct");
+    let do_work = impl_block.children.iter().find(|i| i.name == "do_work").unwrap();
+    assert_eq!(do_work.path, "MyStruct::do_work");
+}
+
+#[test]
+fn to_json_emits_one_record_per_line_with_doc_text() {
+    let parsed = parse(FIXTURE);
+    let ndjson = parsed.to_json();
+    let lines: Vec<&str> = ndjson.lines().collect();
+    assert!(!lines.is_empty());
+    for line in &lines {
+        let value: serde_json::Value = serde_json::from_str(line).expect("each line is valid JSON");
+        assert!(value.get("kind").is_some());
+        assert!(value.get("path").is_some());
+        assert!(value.get("span").is_some());
+    }
+
+    let my_struct_line = lines
+        .iter()
+        .find(|l| l.contains("\"path\":\"MyStruct\""))
+        .expect("MyStruct record present");
+    let value: serde_json::Value = serde_json::from_str(my_struct_line).unwrap();
+    assert!(value["doc"].as_str().unwrap().contains("Holds a single i32 field"));
+}
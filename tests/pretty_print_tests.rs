@@ -0,0 +1,54 @@
+use usereq::parse;
+
+const FIXTURE: &str = include_str!("fixtures/fixture_rust.rs");
+const GOLDEN: &str = include_str!("fixtures/fixture_rust.pretty.txt");
+
+#[test]
+fn pretty_print_matches_the_committed_golden_file() {
+    let parsed = parse(FIXTURE);
+    let actual = parsed.pretty_print(FIXTURE);
+    assert_eq!(
+        actual, GOLDEN,
+        "pretty-printed output drifted from the golden file; if this is an \
+         intentional parser change, update tests/fixtures/fixture_rust.pretty.txt"
+    );
+}
+
+#[test]
+fn pretty_print_is_insensitive_to_reformatting() {
+    // The same declaration written twice with different whitespace and
+    // comment placement; the canonical dump
+    // should come out identical either way.
+    let tight = "pub fn add(a:i32,b:i32)->i32{a+b}";
+    let spaced = "pub fn add(a: i32, b: i32) -> i32 {\n    // a comment\n    a + b\n}";
+    assert_eq!(
+        parse(tight).pretty_print(tight),
+        parse(spaced).pretty_print(spaced)
+    );
+}
+
+#[test]
+fn pretty_print_preserves_where_clause_async_and_extern_markers() {
+    let src = r#"
+        impl<K, V> TypedMap<K, V>
+        where
+            K: std::hash::Hash + Eq,
+        {
+            pub fn get(&self, key: &K) -> Option<&V> {
+                None
+            }
+        }
+
+        pub async fn greet() -> String {
+            String::from("hi")
+        }
+
+        pub extern "C" fn double(x: i32) -> i32 {
+            x * 2
+        }
+    "#;
+    let dump = parse(src).pretty_print(src);
+    assert!(dump.contains("where K: std::hash::Hash + Eq"), "{dump}");
+    assert!(dump.contains("pub async fn greet"), "{dump}");
+    assert!(dump.contains(r#"pub extern "C" fn double"#), "{dump}");
+}
@@ -0,0 +1,79 @@
+use std::path::Path;
+
+use usereq::ast::ItemKind;
+use usereq::workspace::UseOrigin;
+use usereq::Workspace;
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn double_star_walks_every_file_under_a_directory() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/ws_crate/src/**/*.rs");
+    let names: Vec<String> = ws
+        .files
+        .iter()
+        .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert!(names.contains(&"lib.rs".to_string()));
+    assert!(names.contains(&"helper.rs".to_string()));
+    assert!(names.contains(&"mod.rs".to_string()));
+    assert_eq!(ws.files.len(), 3);
+}
+
+#[test]
+fn star_segment_matches_files_but_not_sibling_directories() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/*.rs");
+    let names: Vec<String> = ws
+        .files
+        .iter()
+        .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert!(names.contains(&"fixture_rust.rs".to_string()));
+    assert!(names.contains(&"fixture_rust_err.rs".to_string()));
+    assert!(
+        names.iter().all(|n| n != "mod.rs" && n != "lib.rs" && n != "helper.rs"),
+        "must not descend into ws_crate/: {names:?}"
+    );
+}
+
+#[test]
+fn classifies_use_paths_as_intra_crate_or_external() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/ws_crate/src/lib.rs");
+    assert_eq!(ws.classify_use("std::collections::HashMap"), UseOrigin::External);
+    assert_eq!(ws.classify_use("crate::utils::helper::Helper"), UseOrigin::IntraCrate);
+}
+
+#[test]
+fn classifies_a_bare_module_name_as_intra_crate() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/ws_crate/src/**/*.rs");
+    assert_eq!(ws.classify_use("utils::helper::Helper"), UseOrigin::IntraCrate);
+}
+
+#[test]
+fn finds_files_importing_a_symbol() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/ws_crate/src/**/*.rs");
+    let importers = ws.files_importing("Helper");
+    assert_eq!(importers.len(), 1);
+    assert!(importers[0].ends_with("lib.rs"));
+}
+
+#[test]
+fn resolves_a_nested_path_to_its_item() {
+    let ws = Workspace::from_glob(root(), "tests/fixtures/fixture_rust.rs");
+    let item = ws.resolve("internal::COUNTER").expect("resolves");
+    assert_eq!(item.kind, ItemKind::Static);
+}
+
+#[test]
+fn resolves_a_method_through_a_trait_impl() {
+    // MyStruct has both an inherent impl and `impl MyTrait for MyStruct`;
+    // resolving through the trait impl must not be shadowed by the
+    // inherent one, or vice versa.
+    let ws = Workspace::from_glob(root(), "tests/fixtures/fixture_rust.rs");
+    let do_work = ws.resolve("MyStruct::do_work").expect("resolves");
+    assert_eq!(do_work.kind, ItemKind::Method);
+    let try_update = ws.resolve("MyStruct::try_update").expect("resolves");
+    assert_eq!(try_update.kind, ItemKind::Method);
+}